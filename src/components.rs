@@ -0,0 +1,95 @@
+//! The components that make up every entity in the game's `specs::World`:
+//! the rocket, bullets, enemies, and particles are all just entities built
+//! from some subset of these.
+
+use specs::{Component, NullStorage, VecStorage};
+
+use geometry::Position as Vector;
+
+/// Where an entity is, in world space.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Position(pub Vector);
+
+impl Component for Position {
+    type Storage = VecStorage<Self>;
+}
+
+/// How fast, and in which direction, an entity is currently moving.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Velocity(pub Vector);
+
+impl Component for Velocity {
+    type Storage = VecStorage<Self>;
+}
+
+/// Which way an entity is facing, in radians.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rotation(pub f32);
+
+impl Component for Rotation {
+    type Storage = VecStorage<Self>;
+}
+
+/// Distinguishes the handful of entity kinds that collide with each other,
+/// so `CollisionsSystem` knows which pairs are worth checking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderKind {
+    /// The player-controlled rocket.
+    Player,
+    /// A bullet fired by the player.
+    Bullet,
+    /// An enemy ship.
+    Enemy,
+}
+
+/// Marks an entity as something `CollisionsSystem` should consider, treating
+/// it as a circle of `radius` for the purposes of overlap tests.
+#[derive(Debug, Clone, Copy)]
+pub struct Collider {
+    /// The collision radius, in world units.
+    pub radius: f32,
+    /// What kind of thing this is, so collision pairs can be filtered.
+    pub kind: ColliderKind,
+}
+
+impl Component for Collider {
+    type Storage = VecStorage<Self>;
+}
+
+/// What `view::render_game` should draw an entity as.
+#[derive(Debug, Clone, Copy)]
+pub enum Sprite {
+    /// The player-controlled rocket.
+    Player,
+    /// A bullet fired by the player.
+    Bullet,
+    /// An enemy ship.
+    Enemy,
+}
+
+impl Component for Sprite {
+    type Storage = VecStorage<Self>;
+}
+
+/// Marks an entity that should be removed once its remaining time reaches
+/// zero, such as the particles spawned by an explosion.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lifetime {
+    /// Remaining lifetime, in seconds.
+    pub ttl: f32,
+}
+
+impl Component for Lifetime {
+    type Storage = VecStorage<Self>;
+}
+
+/// Marks the handful of entities that are never drawn or collided with, but
+/// still need to be simulated, e.g. the particles thrown out by an
+/// explosion. Kept separate from `ColliderKind`/`Sprite` since particles
+/// carry neither.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Particle;
+
+impl Component for Particle {
+    type Storage = NullStorage<Self>;
+}
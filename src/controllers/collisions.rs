@@ -0,0 +1,86 @@
+//! Detects and resolves overlaps between bullets and enemies.
+
+use std::collections::HashSet;
+
+use specs::{Entities, Join, LazyUpdate, Read, ReadStorage, System, Write};
+
+use components::{Collider, ColliderKind, Lifetime, Particle, Position, Velocity};
+use controllers::{Event, EventBuffer, Message, Score};
+
+/// How long a particle thrown out by an explosion lives, in seconds.
+const PARTICLE_TTL: f32 = 0.5;
+
+/// Checks every bullet against every enemy, removing anything that was hit
+/// and spawning an explosion of particles plus an `Event::Explosion` in its
+/// place.
+pub struct CollisionsSystem;
+
+impl<'a> System<'a> for CollisionsSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, LazyUpdate>,
+        Write<'a, Score>,
+        Write<'a, Message>,
+        Write<'a, EventBuffer>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, Collider>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, lazy, mut score, mut message, mut event_buffer, positions, velocities, colliders) = data;
+
+        let mut hit_bullets = Vec::new();
+        let mut hit_enemies = Vec::new();
+        // Tracks enemies already matched this frame, so a second bullet (or
+        // a bullet within range of more than one enemy) doesn't count the
+        // same kill twice.
+        let mut hit_enemy_entities = HashSet::new();
+
+        for (bullet_entity, bullet_position, bullet_collider) in (&*entities, &positions, &colliders).join() {
+            if bullet_collider.kind != ColliderKind::Bullet {
+                continue;
+            }
+            for (enemy_entity, enemy_position, enemy_collider) in (&*entities, &positions, &colliders).join() {
+                if enemy_collider.kind != ColliderKind::Enemy || hit_enemy_entities.contains(&enemy_entity) {
+                    continue;
+                }
+                let distance = (bullet_position.0 - enemy_position.0).length();
+                if distance <= bullet_collider.radius + enemy_collider.radius {
+                    hit_bullets.push(bullet_entity);
+                    hit_enemy_entities.insert(enemy_entity);
+                    let velocity = velocities.get(enemy_entity).cloned().unwrap_or_default();
+                    hit_enemies.push((enemy_entity, enemy_position.0, velocity));
+                }
+            }
+        }
+
+        let destroyed_enemies = hit_enemies.len();
+        for (enemy_entity, position, velocity) in hit_enemies {
+            let _ = entities.delete(enemy_entity);
+            score.0 += 1;
+            debug!("enemy destroyed at {:?}, score is now {}", position, score.0);
+
+            // Spawned via `LazyUpdate` rather than inserted directly, since
+            // `Lifetime`/`Particle` aren't part of this system's storages.
+            let particle = entities.create();
+            lazy.insert(particle, Position(position));
+            lazy.insert(particle, velocity);
+            lazy.insert(particle, Lifetime { ttl: PARTICLE_TTL });
+            lazy.insert(particle, Particle);
+
+            event_buffer.0.push(Event::Explosion { position });
+        }
+
+        for bullet_entity in hit_bullets {
+            let _ = entities.delete(bullet_entity);
+        }
+
+        let remaining_enemies = (&colliders).join()
+            .filter(|collider| collider.kind == ColliderKind::Enemy)
+            .count() - destroyed_enemies;
+        if message.0.is_none() && remaining_enemies == 0 {
+            message.0 = Some("You win! Press any key to play again.".to_owned());
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Turns raw keyboard/gamepad/mouse input into the `Actions` resource the
+//! rest of the simulation reacts to.
+
+use ggez::event::{Axis, Button, Keycode, MouseButton, Mod};
+use specs::{Read, System, Write};
+
+use controllers::Actions;
+use geometry::Position;
+
+/// Ignore stick deflection smaller than this, so a controller that doesn't
+/// rest perfectly at zero doesn't cause permanent drift.
+const AXIS_DEADZONE: f32 = 0.15;
+
+/// ggez (via SDL2) reports axis values in `i16::MIN..=i16::MAX`.
+const AXIS_MAX: f32 = 32_767.0;
+
+/// Keeps track of which keys are held and the state of a connected gamepad
+/// and mouse. Mutated directly by `ApplicationState`'s ggez event handlers
+/// (which run outside the dispatcher), and turned into `Actions` each frame
+/// by `InputSystem`. A `World` resource.
+#[derive(Default)]
+pub struct InputState {
+    left_key: bool,
+    right_key: bool,
+    thrust_key: bool,
+    shoot_key: bool,
+
+    // Raw, deadzone-filtered gamepad axes, in -1.0..=1.0.
+    turn_axis: f32,
+    thrust_axis: f32,
+    shoot_button: bool,
+
+    // `None` until the mouse has moved at least once; once set, it takes
+    // over aiming from the keyboard/gamepad turn axes.
+    cursor: Option<Position>,
+    shoot_mouse: bool,
+}
+
+impl InputState {
+    /// Called when a key is pressed.
+    pub fn key_press(&mut self, keycode: Keycode, _keymod: Mod) {
+        self.set_key(keycode, true);
+    }
+
+    /// Called when a key is released.
+    pub fn key_release(&mut self, keycode: Keycode, _keymod: Mod) {
+        self.set_key(keycode, false);
+    }
+
+    fn set_key(&mut self, keycode: Keycode, pressed: bool) {
+        match keycode {
+            Keycode::Left | Keycode::A => self.left_key = pressed,
+            Keycode::Right | Keycode::D => self.right_key = pressed,
+            Keycode::Up | Keycode::W => self.thrust_key = pressed,
+            Keycode::Space => self.shoot_key = pressed,
+            _ => (),
+        }
+    }
+
+    /// Called when a gamepad button is pressed.
+    pub fn controller_button_down(&mut self, button: Button) {
+        if let Button::A = button {
+            self.shoot_button = true;
+        }
+    }
+
+    /// Called when a gamepad button is released.
+    pub fn controller_button_up(&mut self, button: Button) {
+        if let Button::A = button {
+            self.shoot_button = false;
+        }
+    }
+
+    /// Called when a gamepad axis moves. `value` is in `i16::MIN..=i16::MAX`
+    /// for sticks, but `0..=i16::MAX` for triggers, which never report
+    /// negative.
+    pub fn controller_axis(&mut self, axis: Axis, value: i16) {
+        match axis {
+            Axis::LeftX => self.turn_axis = Self::apply_deadzone(f32::from(value) / AXIS_MAX),
+            Axis::TriggerRight => self.thrust_axis = Self::apply_deadzone(f32::from(value) / AXIS_MAX),
+            _ => (),
+        }
+    }
+
+    /// Called when the mouse moves, in window coordinates (which line up
+    /// 1:1 with world coordinates).
+    pub fn mouse_motion(&mut self, x: i32, y: i32) {
+        self.cursor = Some(position!(x as f32, y as f32));
+    }
+
+    /// Called when a mouse button is pressed.
+    pub fn mouse_button_down(&mut self, button: MouseButton) {
+        if let MouseButton::Left = button {
+            self.shoot_mouse = true;
+        }
+    }
+
+    /// Called when a mouse button is released.
+    pub fn mouse_button_up(&mut self, button: MouseButton) {
+        if let MouseButton::Left = button {
+            self.shoot_mouse = false;
+        }
+    }
+
+    fn apply_deadzone(value: f32) -> f32 {
+        if value.abs() < AXIS_DEADZONE {
+            0.0
+        } else {
+            value
+        }
+    }
+
+    /// The actions the player is currently performing, combining keyboard
+    /// (digital) and gamepad (analogue) input.
+    fn actions(&self) -> Actions {
+        let key_turn = match (self.left_key, self.right_key) {
+            (true, false) => -1.0,
+            (false, true) => 1.0,
+            _ => 0.0,
+        };
+        let key_thrust = if self.thrust_key { 1.0 } else { 0.0 };
+
+        // Whichever input source has the larger magnitude wins, so a
+        // gamepad resting at zero never fights with active keyboard input.
+        let turn = if self.turn_axis.abs() > key_turn.abs() {
+            self.turn_axis
+        } else {
+            key_turn
+        };
+        let thrust = if self.thrust_axis > key_thrust {
+            self.thrust_axis
+        } else {
+            key_thrust
+        };
+
+        Actions {
+            turn,
+            thrust,
+            shoot: self.shoot_key || self.shoot_button || self.shoot_mouse,
+            cursor: self.cursor,
+        }
+    }
+}
+
+/// Reads the `InputState` resource and turns it into the `Actions` resource
+/// that `TimeSystem` acts on.
+pub struct InputSystem;
+
+impl<'a> System<'a> for InputSystem {
+    type SystemData = (Read<'a, InputState>, Write<'a, Actions>);
+
+    fn run(&mut self, (input_state, mut actions): Self::SystemData) {
+        *actions = input_state.actions();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_deadzone_zeroes_small_deflection() {
+        assert_eq!(InputState::apply_deadzone(AXIS_DEADZONE / 2.0), 0.0);
+        assert_eq!(InputState::apply_deadzone(-AXIS_DEADZONE / 2.0), 0.0);
+    }
+
+    #[test]
+    fn apply_deadzone_passes_through_large_deflection() {
+        assert_eq!(InputState::apply_deadzone(0.5), 0.5);
+    }
+
+    #[test]
+    fn actions_prefers_the_larger_magnitude_turn_input() {
+        let mut state = InputState::default();
+        state.left_key = true;
+        state.turn_axis = 0.5;
+        // Keyboard snaps to -1.0, which is larger in magnitude than the
+        // gamepad's 0.5, so it should win.
+        assert_eq!(state.actions().turn, -1.0);
+
+        let mut state = InputState::default();
+        state.turn_axis = 0.8;
+        assert_eq!(state.actions().turn, 0.8);
+    }
+
+    #[test]
+    fn actions_prefers_the_larger_thrust_input() {
+        let mut state = InputState::default();
+        state.thrust_key = true;
+        state.thrust_axis = 0.25;
+        assert_eq!(state.actions().thrust, 1.0);
+    }
+
+    #[test]
+    fn actions_combines_shoot_from_every_input_source() {
+        let mut state = InputState::default();
+        state.shoot_key = true;
+        assert!(state.actions().shoot);
+
+        let mut state = InputState::default();
+        state.shoot_button = true;
+        assert!(state.actions().shoot);
+
+        let mut state = InputState::default();
+        state.shoot_mouse = true;
+        assert!(state.actions().shoot);
+    }
+}
@@ -0,0 +1,105 @@
+//! Controllers translate input and the passage of time into changes to the
+//! simulation `World`. Each controller is a specs `System` owning one
+//! concern: `InputSystem` turns raw input into the `Actions` resource,
+//! `TimeSystem` advances the simulation, and `CollisionsSystem` detects and
+//! resolves overlaps between entities. They run each frame through a single
+//! `Dispatcher`, built in `main`.
+
+mod collisions;
+mod input;
+mod time;
+
+pub use self::collisions::CollisionsSystem;
+pub use self::input::{InputState, InputSystem};
+pub use self::time::TimeSystem;
+
+use std::time::Duration;
+
+use geometry::Position;
+
+/// The actions the player is currently performing, derived from whichever
+/// input device(s) are in use. `turn` and `thrust` are analogue so that
+/// gamepad sticks can drive them proportionally rather than just on/off;
+/// keyboard input simply snaps them to -1.0/0.0/1.0. A `World` resource,
+/// written by `InputSystem` and read by `TimeSystem`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Actions {
+    /// Desired turn rate, from -1.0 (full left) to 1.0 (full right).
+    pub turn: f32,
+    /// Desired thrust, from 0.0 (none) to 1.0 (full).
+    pub thrust: f32,
+    /// Whether the player is currently firing.
+    pub shoot: bool,
+    /// Where the mouse is, if it has moved since the game started; when
+    /// present, it takes over aiming from `turn` so the rocket turns to
+    /// face the cursor (twin-stick style) rather than turning at a fixed
+    /// rate.
+    pub cursor: Option<Position>,
+}
+
+/// Something that happened in the simulation this frame. Pushed onto the
+/// `EventBuffer` resource by `CollisionsSystem`/`TimeSystem`, and drained by
+/// `view::play_sounds` so that sound playback stays decoupled from
+/// simulation systems. Events that make a sound carry the world position
+/// they happened at, so `view::play_sounds` can pan and attenuate the sound
+/// relative to the player.
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+    /// The game was restarted by `ApplicationState::reset`. Not pushed for
+    /// the first game, which is built by `game_state::build_world`.
+    GameStart,
+    /// The player fired a bullet, from `position`.
+    Shot {
+        /// Where the shot was fired from.
+        position: Position,
+    },
+    /// An enemy was destroyed by a bullet, at `position`.
+    Explosion {
+        /// Where the explosion happened.
+        position: Position,
+    },
+}
+
+/// The events produced so far this frame, drained and acted on by
+/// `view::play_sounds` after each `update`. A `World` resource.
+#[derive(Debug, Clone, Default)]
+pub struct EventBuffer(pub Vec<Event>);
+
+/// How long the last frame took, in seconds. A `World` resource, set in
+/// `ApplicationState::update` before the dispatcher runs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeltaTime(pub f32);
+
+impl DeltaTime {
+    /// Converts a ggez frame `Duration` into seconds.
+    pub fn from_duration(duration: Duration) -> DeltaTime {
+        DeltaTime(duration.as_secs() as f32 + duration.subsec_nanos() as f32 / 1_000_000_000.0)
+    }
+}
+
+/// The player's score this game: one point per enemy destroyed. A `World`
+/// resource.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Score(pub u32);
+
+/// When set, the next key press runs `ApplicationState::reset`. Setting it
+/// does not pause the simulation, and `view::render_game` does not draw the
+/// text. A `World` resource.
+#[derive(Debug, Clone, Default)]
+pub struct Message(pub Option<String>);
+
+/// Minimum time between shots, in seconds. Also `ShotCooldown`'s starting
+/// value, so the player can fire immediately at the start of a game.
+pub const SHOT_COOLDOWN: f32 = 0.2;
+
+/// Seconds since the player last fired; compared against `SHOT_COOLDOWN` by
+/// `TimeSystem` to rate-limit shooting. A `World` resource, so
+/// `game_state::reset` can put it back to a ready-to-fire state.
+#[derive(Debug, Clone, Copy)]
+pub struct ShotCooldown(pub f32);
+
+impl Default for ShotCooldown {
+    fn default() -> ShotCooldown {
+        ShotCooldown(SHOT_COOLDOWN)
+    }
+}
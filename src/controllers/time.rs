@@ -0,0 +1,129 @@
+//! Advances the simulation by a `DeltaTime` each frame.
+
+use specs::{Builder, Entities, Join, Read, System, Write, WriteStorage};
+
+use components::{Collider, ColliderKind, Lifetime, Position, Rotation, Sprite, Velocity};
+use controllers::{Actions, DeltaTime, Event, EventBuffer, ShotCooldown, SHOT_COOLDOWN};
+use game_state::PlayerEntity;
+use geometry::{Position as Vector, Size};
+use util::{clamp, normalize_angle};
+
+/// Radians per second the rocket can turn at full deflection.
+const TURN_RATE: f32 = 3.0;
+/// Units per second squared the rocket accelerates at full thrust.
+const THRUST_ACCEL: f32 = 220.0;
+/// Units per second bullets travel.
+const BULLET_SPEED: f32 = 600.0;
+/// Collision radius given to newly spawned bullets.
+const BULLET_RADIUS: f32 = 2.0;
+
+/// Moves the player according to input, advances bullets/particles, and
+/// spawns new bullets when the player shoots. Enemies don't move once
+/// spawned, so they're left untouched here.
+pub struct TimeSystem;
+
+impl<'a> System<'a> for TimeSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, DeltaTime>,
+        Read<'a, Actions>,
+        Read<'a, Size>,
+        Read<'a, PlayerEntity>,
+        Write<'a, ShotCooldown>,
+        Write<'a, EventBuffer>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, Velocity>,
+        WriteStorage<'a, Rotation>,
+        WriteStorage<'a, Collider>,
+        WriteStorage<'a, Sprite>,
+        WriteStorage<'a, Lifetime>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            dt,
+            actions,
+            world_size,
+            player_entity,
+            mut shot_cooldown,
+            mut event_buffer,
+            mut positions,
+            mut velocities,
+            mut rotations,
+            mut colliders,
+            mut sprites,
+            mut lifetimes,
+        ) = data;
+        let seconds = dt.0;
+        let max_turn = TURN_RATE * seconds;
+        let player = player_entity.0.expect("player entity not spawned");
+
+        let rotation = {
+            let player_position = positions.get(player).expect("player has no Position").0;
+            let current_rotation = rotations.get(player).expect("player has no Rotation").0;
+            match actions.cursor {
+                Some(cursor) => {
+                    let to_cursor = cursor - player_position;
+                    let desired_rotation = to_cursor.y.atan2(to_cursor.x);
+                    current_rotation + clamp(normalize_angle(desired_rotation - current_rotation), -max_turn, max_turn)
+                },
+                None => current_rotation + actions.turn * max_turn,
+            }
+        };
+        rotations.get_mut(player).expect("player has no Rotation").0 = rotation;
+
+        let thrust_direction = Vector::from_angle(rotation);
+        {
+            let player_velocity = &mut velocities.get_mut(player).expect("player has no Velocity").0;
+            *player_velocity = *player_velocity + thrust_direction * (actions.thrust * THRUST_ACCEL * seconds);
+        }
+        let player_velocity = velocities.get(player).expect("player has no Velocity").0;
+        {
+            let player_position = &mut positions.get_mut(player).expect("player has no Position").0;
+            *player_position = *player_position + player_velocity * seconds;
+        }
+        let player_position = positions.get(player).expect("player has no Position").0;
+
+        shot_cooldown.0 += seconds;
+        if actions.shoot && shot_cooldown.0 >= SHOT_COOLDOWN {
+            shot_cooldown.0 = 0.0;
+            let direction = Vector::from_angle(rotation);
+            entities.build_entity()
+                .with(Position(player_position), &mut positions)
+                .with(Velocity(direction * BULLET_SPEED), &mut velocities)
+                .with(Collider { radius: BULLET_RADIUS, kind: ColliderKind::Bullet }, &mut colliders)
+                .with(Sprite::Bullet, &mut sprites)
+                .build();
+            debug!("player fired at {:?}", player_position);
+            event_buffer.0.push(Event::Shot { position: player_position });
+        }
+
+        let mut expired = Vec::new();
+        for (entity, position, velocity, collider) in (&*entities, &mut positions, &velocities, (&colliders).maybe()).join() {
+            if entity == player {
+                continue;
+            }
+            position.0 = position.0 + velocity.0 * seconds;
+
+            if let Some(collider) = collider {
+                if collider.kind == ColliderKind::Bullet && !in_bounds(position.0, *world_size) {
+                    expired.push(entity);
+                }
+            }
+        }
+        for (entity, lifetime) in (&*entities, &mut lifetimes).join() {
+            lifetime.ttl -= seconds;
+            if lifetime.ttl <= 0.0 {
+                expired.push(entity);
+            }
+        }
+        for entity in expired {
+            entities.delete(entity).expect("entity already deleted");
+        }
+    }
+}
+
+fn in_bounds(position: Vector, world_size: Size) -> bool {
+    position.x >= 0.0 && position.x <= world_size.width && position.y >= 0.0 && position.y <= world_size.height
+}
@@ -0,0 +1,93 @@
+//! Builds the specs `World` that holds every entity and resource describing
+//! the game currently being played, and spawns (or respawns) the player and
+//! enemies into it.
+
+use rand::{Rng, ThreadRng};
+use specs::{Builder, Entity, World};
+
+use components::{Collider, ColliderKind, Lifetime, Particle, Position, Rotation, Sprite, Velocity};
+use controllers::{Actions, DeltaTime, EventBuffer, InputState, Message, Score, ShotCooldown};
+use geometry::{Position as Vector, Size};
+
+/// How many enemies are spawned at the start of each game.
+const ENEMY_COUNT: usize = 4;
+/// Collision radius given to the player.
+const PLAYER_RADIUS: f32 = 10.0;
+/// Collision radius given to enemies.
+const ENEMY_RADIUS: f32 = 12.0;
+
+/// The entity tracked as "the player" this game, so systems that only ever
+/// care about the one rocket don't have to join over every `Collider` to
+/// find it. A `World` resource; always `Some` once the world has been built.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlayerEntity(pub Option<Entity>);
+
+/// Registers every component type and inserts every resource the simulation
+/// needs, then spawns the player and the starting wave of enemies.
+pub fn build_world(world_size: Size, rng: &mut ThreadRng) -> World {
+    let mut world = World::new();
+
+    world.register::<Position>();
+    world.register::<Velocity>();
+    world.register::<Rotation>();
+    world.register::<Collider>();
+    world.register::<Sprite>();
+    world.register::<Lifetime>();
+    world.register::<Particle>();
+
+    world.add_resource(world_size);
+    world.add_resource(InputState::default());
+    world.add_resource(Actions::default());
+    world.add_resource(DeltaTime::default());
+    world.add_resource(EventBuffer::default());
+    world.add_resource(Score::default());
+    world.add_resource(Message::default());
+    world.add_resource(ShotCooldown::default());
+    world.add_resource(PlayerEntity::default());
+
+    spawn_world(&mut world, rng);
+    world
+}
+
+/// Resets the game back to its starting conditions, e.g. after a game-over
+/// message has been acknowledged: every entity is removed and the player
+/// and a fresh wave of enemies are spawned in their place.
+pub fn reset(world: &mut World, rng: &mut ThreadRng) {
+    world.delete_all();
+    *world.write_resource::<Score>() = Score::default();
+    *world.write_resource::<Message>() = Message::default();
+    *world.write_resource::<ShotCooldown>() = ShotCooldown::default();
+    spawn_world(world, rng);
+}
+
+fn spawn_world(world: &mut World, rng: &mut ThreadRng) {
+    let world_size = *world.read_resource::<Size>();
+
+    let player = world.create_entity()
+        .with(Position(position!(world_size.width / 2.0, world_size.height / 2.0)))
+        .with(Velocity(Vector::zero()))
+        .with(Rotation(0.0))
+        .with(Collider { radius: PLAYER_RADIUS, kind: ColliderKind::Player })
+        .with(Sprite::Player)
+        .build();
+    *world.write_resource::<PlayerEntity>() = PlayerEntity(Some(player));
+
+    spawn_enemies(world, world_size, rng);
+}
+
+/// Spreads `ENEMY_COUNT` enemies evenly along the top edge, then nudges
+/// each down by a random amount so they don't spawn in a perfectly flat
+/// line.
+fn spawn_enemies(world: &mut World, world_size: Size, rng: &mut ThreadRng) {
+    use itertools_num::linspace;
+
+    for x in linspace(0.0, world_size.width, ENEMY_COUNT) {
+        world.create_entity()
+            .with(Position(position!(x, rng.gen_range(0.0, world_size.height / 4.0))))
+            .with(Velocity(Vector::zero()))
+            .with(Rotation(rng.gen_range(0.0, ::std::f32::consts::PI * 2.0)))
+            .with(Collider { radius: ENEMY_RADIUS, kind: ColliderKind::Enemy })
+            .with(Sprite::Enemy)
+            .build();
+    }
+}
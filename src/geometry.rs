@@ -0,0 +1,107 @@
+//! Basic geometric primitives shared by the rest of the game.
+
+use std::ops::{Add, Sub, Mul};
+
+/// Builds a `Position` without having to name the fields.
+macro_rules! position {
+    ($x:expr, $y:expr) => {
+        $crate::geometry::Position { x: $x, y: $y }
+    };
+}
+
+/// A 2D size, used for the window and for anything that needs bounds.
+/// `Default` so it can be stored as a `specs::World` resource.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Size {
+    /// Width, in pixels.
+    pub width: f32,
+    /// Height, in pixels.
+    pub height: f32,
+}
+
+impl Size {
+    /// Creates a new `Size`.
+    pub fn new(width: f32, height: f32) -> Self {
+        Size { width, height }
+    }
+}
+
+/// A 2D position / vector. Used for anything that moves: players, bullets,
+/// enemies, particles, and sound sources.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Position {
+    /// Horizontal component.
+    pub x: f32,
+    /// Vertical component.
+    pub y: f32,
+}
+
+impl Position {
+    /// The origin.
+    pub fn zero() -> Self {
+        Position { x: 0.0, y: 0.0 }
+    }
+
+    /// Builds a unit vector pointing in `angle` radians (0 points along +x).
+    pub fn from_angle(angle: f32) -> Self {
+        Position { x: angle.cos(), y: angle.sin() }
+    }
+
+    /// The length of the vector.
+    pub fn length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+    fn add(self, rhs: Position) -> Position {
+        position!(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Position {
+    type Output = Position;
+    fn sub(self, rhs: Position) -> Position {
+        position!(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<f32> for Position {
+    type Output = Position;
+    fn mul(self, rhs: f32) -> Position {
+        position!(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_sums_components() {
+        assert_eq!(position!(1.0, 2.0) + position!(3.0, 4.0), position!(4.0, 6.0));
+    }
+
+    #[test]
+    fn sub_subtracts_components() {
+        assert_eq!(position!(3.0, 4.0) - position!(1.0, 2.0), position!(2.0, 2.0));
+    }
+
+    #[test]
+    fn mul_scales_both_components() {
+        assert_eq!(position!(1.0, 2.0) * 2.0, position!(2.0, 4.0));
+    }
+
+    #[test]
+    fn length_is_the_vector_magnitude() {
+        assert_eq!(position!(3.0, 4.0).length(), 5.0);
+    }
+
+    #[test]
+    fn from_angle_builds_a_unit_vector() {
+        let unit = Position::from_angle(0.0);
+        assert!((unit.x - 1.0).abs() < 1e-6);
+        assert!(unit.y.abs() < 1e-6);
+    }
+}
@@ -6,26 +6,31 @@
 #![feature(nll)]
 #![feature(slice_patterns)]
 
+extern crate chrono;
+extern crate fern;
 extern crate ggez;
 extern crate itertools_num;
+#[macro_use]
+extern crate log;
 extern crate rand;
+extern crate specs;
 
 // Note: we need to load `geometry` first so the macro is available for
 // the modules that come afterwards
 #[macro_use]
 mod geometry;
+mod components;
 mod controllers;
 mod view;
 mod game_state;
-mod models;
 mod util;
 
-use ggez::event::{self, Keycode, Mod};
+use ggez::event::{self, Axis, Button, Keycode, MouseButton, Mod};
 use ggez::{Context, GameResult};
 use rand::ThreadRng;
+use specs::{Dispatcher, DispatcherBuilder, World};
 
-use controllers::{CollisionsController, Event, InputController, TimeController};
-use game_state::GameState;
+use controllers::{CollisionsSystem, DeltaTime, Event, EventBuffer, InputState, InputSystem, Message, TimeSystem};
 use geometry::Size;
 use view::Resources;
 
@@ -38,15 +43,12 @@ pub struct ApplicationState {
     has_focus: bool,
     // Resources holds our loaded font, images and sounds
     resources: Resources,
-    // The game state contains all information needed to run the game
-    game_state: GameState,
-    // The time controller modifies the game state as time passes
-    time_controller: TimeController,
-    // The input controller keeps track of the actions that are triggered by the player
-    input_controller: InputController,
-    // The event buffer keeps track of events that trigger sounds, so we can separate
-    // sound playing from the game logic
-    event_buffer: Vec<Event>,
+    // The specs `World` holds every entity and resource describing the game
+    // currently being played
+    world: World,
+    // Runs the input/time/collisions systems over `world` each update, in
+    // dependency order
+    dispatcher: Dispatcher<'static, 'static>,
     // A source of randomness
     rng: ThreadRng,
 }
@@ -55,13 +57,17 @@ impl ApplicationState {
     /// Simply creates a new application state
     fn new(ctx: &mut Context, game_size: Size) -> GameResult<ApplicationState> {
         let mut rng = rand::thread_rng();
+        let world = game_state::build_world(game_size, &mut rng);
+        let dispatcher = DispatcherBuilder::new()
+            .with(InputSystem, "input", &[])
+            .with(TimeSystem, "time", &["input"])
+            .with(CollisionsSystem, "collisions", &["time"])
+            .build();
         let app_state = ApplicationState {
             has_focus: true,
             resources: Resources::new(ctx),
-            game_state: GameState::new(game_size, &mut rng),
-            time_controller: TimeController::new(),
-            input_controller: InputController::new(),
-            event_buffer: Vec::new(),
+            world,
+            dispatcher,
             rng,
         };
         Ok(app_state)
@@ -69,13 +75,13 @@ impl ApplicationState {
 
     /// This will be called when the game needs to be reset
     fn reset(&mut self) {
-        // Reset time controller
-        self.time_controller.reset();
+        info!("resetting game");
 
-        // Reset game state
-        self.game_state.reset(&mut self.rng);
+        // Reset the world: every entity is removed and the player and a
+        // fresh wave of enemies are spawned in their place
+        game_state::reset(&mut self.world, &mut self.rng);
 
-        self.event_buffer.push(Event::GameStart);
+        self.world.write_resource::<EventBuffer>().0.push(Event::GameStart);
     }
 }
 
@@ -89,41 +95,79 @@ impl event::EventHandler for ApplicationState {
             return Ok(())
         }
 
-        // Update game state, and check for collisions
+        // Advance `DeltaTime`, then run the input/time/collisions systems over `world`
         let duration = ggez::timer::get_delta(ctx);
-        self.time_controller.update_seconds(
-            duration,
-            self.input_controller.actions(),
-            &mut self.game_state,
-            &mut self.event_buffer,
-            &mut self.rng
-        );
+        trace!("dt = {:?}", duration);
+        *self.world.write_resource::<DeltaTime>() = DeltaTime::from_duration(duration);
 
-        CollisionsController::handle_collisions(&mut self.game_state, &mut self.time_controller, &mut self.event_buffer);
+        self.dispatcher.dispatch(&self.world.res);
+        self.world.maintain();
 
         Ok(())
     }
 
     // This is called when ggez wants us to draw our game
     fn draw(&mut self, ctx: &mut Context) -> GameResult<()> {
-        view::play_sounds(&mut self.event_buffer, &mut self.resources)?;
+        let player_position = view::player_position(self);
+        let world_size = *self.world.read_resource::<Size>();
+        {
+            let mut event_buffer = self.world.write_resource::<EventBuffer>();
+            view::play_sounds(&mut event_buffer, &mut self.resources, player_position, world_size)?;
+        }
         view::render_game(self, ctx)
     }
 
     // Listen for keyboard events
     fn key_down_event(&mut self, _ctx: &mut Context, keycode: Keycode, keymod: Mod, _repeat: bool) {
+        debug!("key pressed: {:?}", keycode);
+
         // If we're displaying a message (waiting for user input) then hide it and reset the game
-        if let Some(_) = self.game_state.message {
+        if self.world.read_resource::<Message>().0.is_some() {
             self.reset();
         }
-        self.input_controller.key_press(keycode, keymod);
+        self.world.write_resource::<InputState>().key_press(keycode, keymod);
     }
     fn key_up_event(&mut self, _ctx: &mut Context, keycode: Keycode, keymod: Mod, _repeat: bool) {
-        self.input_controller.key_release(keycode, keymod);
+        debug!("key released: {:?}", keycode);
+        self.world.write_resource::<InputState>().key_release(keycode, keymod);
+    }
+
+    // Listen for gamepad buttons, translated into the same actions as the keyboard
+    fn controller_button_down_event(&mut self, _ctx: &mut Context, btn: Button, _instance_id: i32) {
+        debug!("controller button pressed: {:?}", btn);
+        self.world.write_resource::<InputState>().controller_button_down(btn);
+    }
+    fn controller_button_up_event(&mut self, _ctx: &mut Context, btn: Button, _instance_id: i32) {
+        debug!("controller button released: {:?}", btn);
+        self.world.write_resource::<InputState>().controller_button_up(btn);
+    }
+
+    // Listen for gamepad sticks/triggers, so thrust/turn can be proportional
+    fn controller_axis_event(&mut self, _ctx: &mut Context, axis: Axis, value: i16, _instance_id: i32) {
+        // Traced rather than debug-logged: sticks report continuously while
+        // held, same reasoning as the per-frame `dt` trace in `update`.
+        trace!("controller axis {:?} = {}", axis, value);
+        self.world.write_resource::<InputState>().controller_axis(axis, value);
+    }
+
+    // Listen for the mouse, so the player can aim and fire with it
+    fn mouse_motion_event(&mut self, _ctx: &mut Context, _state: event::MouseState, x: i32, y: i32, _xrel: i32, _yrel: i32) {
+        // Traced rather than debug-logged: fires on every frame the mouse moves.
+        trace!("mouse moved to ({}, {})", x, y);
+        self.world.write_resource::<InputState>().mouse_motion(x, y);
+    }
+    fn mouse_button_down_event(&mut self, _ctx: &mut Context, button: MouseButton, _x: i32, _y: i32) {
+        debug!("mouse button pressed: {:?}", button);
+        self.world.write_resource::<InputState>().mouse_button_down(button);
+    }
+    fn mouse_button_up_event(&mut self, _ctx: &mut Context, button: MouseButton, _x: i32, _y: i32) {
+        debug!("mouse button released: {:?}", button);
+        self.world.write_resource::<InputState>().mouse_button_up(button);
     }
 
     // Listen for window focus to pause the game's execution
     fn focus_event(&mut self, _ctx: &mut Context, has_focus: bool) {
+        info!("window focus changed: {}", has_focus);
         self.has_focus = has_focus;
     }
 }
@@ -161,7 +205,62 @@ impl Args {
     }
 }
 
+/// Path of the log file `setup_logging` writes to.
+const LOG_FILE: &str = "rocket.log";
+/// How many rotated-out copies of `LOG_FILE` (`rocket.log.1`, `.2`, ...) to
+/// keep around before the oldest is discarded.
+const LOG_FILE_BACKUPS: u32 = 5;
+
+/// Renames `LOG_FILE` to `LOG_FILE.1`, shifting any existing `LOG_FILE.N` up
+/// to `LOG_FILE.N+1` first (discarding anything beyond `LOG_FILE_BACKUPS`),
+/// so each run starts with a fresh file instead of appending forever.
+fn rotate_log_file() -> std::io::Result<()> {
+    let oldest = format!("{}.{}", LOG_FILE, LOG_FILE_BACKUPS);
+    if std::path::Path::new(&oldest).exists() {
+        std::fs::remove_file(&oldest)?;
+    }
+    for generation in (1..LOG_FILE_BACKUPS).rev() {
+        let from = format!("{}.{}", LOG_FILE, generation);
+        let to = format!("{}.{}", LOG_FILE, generation + 1);
+        if std::path::Path::new(&from).exists() {
+            std::fs::rename(from, to)?;
+        }
+    }
+    if std::path::Path::new(LOG_FILE).exists() {
+        std::fs::rename(LOG_FILE, format!("{}.1", LOG_FILE))?;
+    }
+    Ok(())
+}
+
+/// Sets up logging to both the console and `rocket.log`, timestamped and
+/// leveled, so crashes and gameplay can be traced after the fact without
+/// attaching a debugger. Rotates out the previous run's log file first, so
+/// `rocket.log` doesn't grow without bound across runs.
+fn setup_logging() -> Result<(), fern::InitError> {
+    rotate_log_file()?;
+
+    fern::Dispatch::new()
+        .format(|out, message, record| {
+            out.finish(format_args!(
+                "{} [{}] {}: {}",
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+                record.level(),
+                record.target(),
+                message
+            ))
+        })
+        .level(log::LevelFilter::Debug)
+        .level_for("rocket", log::LevelFilter::Trace)
+        .chain(std::io::stdout())
+        .chain(fern::log_file(LOG_FILE)?)
+        .apply()?;
+    Ok(())
+}
+
 fn main() {
+    setup_logging().expect("failed to initialize logging");
+    info!("starting Rocket");
+
     let args = Args::parse(std::env::args());
 
     // Create the rendering context and set the background color to black
@@ -170,8 +269,8 @@ fn main() {
     // Load the application state and start the event loop
     let state = &mut ApplicationState::new(ctx, args.game_size).unwrap();
     if let Err(err) = event::run(ctx, state) {
-        println!("Error encountered: {}", err);
+        error!("error encountered: {}", err);
     } else {
-        println!("Exited cleanly, thanks for playing Rocket!");
+        info!("exited cleanly");
     }
 }
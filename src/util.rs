@@ -0,0 +1,67 @@
+//! Small helpers that don't belong to any one module.
+
+/// Clamps `value` into the inclusive range `[min, max]`.
+pub fn clamp(value: f32, min: f32, max: f32) -> f32 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Wraps `value` into the `[0, modulus)` range, handling negative values.
+pub fn wrap(value: f32, modulus: f32) -> f32 {
+    let remainder = value % modulus;
+    if remainder < 0.0 {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+/// Normalizes an angle, in radians, into the `[-PI, PI)` range, so the
+/// shortest turn direction can be found by simple subtraction.
+pub fn normalize_angle(angle: f32) -> f32 {
+    use std::f32::consts::PI;
+    wrap(angle + PI, PI * 2.0) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn clamp_leaves_in_range_values_untouched() {
+        assert_eq!(clamp(0.5, 0.0, 1.0), 0.5);
+    }
+
+    #[test]
+    fn clamp_caps_values_outside_the_range() {
+        assert_eq!(clamp(-1.0, 0.0, 1.0), 0.0);
+        assert_eq!(clamp(2.0, 0.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn wrap_leaves_in_range_values_untouched() {
+        assert_eq!(wrap(1.5, 2.0), 1.5);
+    }
+
+    #[test]
+    fn wrap_handles_negative_values() {
+        assert_eq!(wrap(-0.5, 2.0), 1.5);
+    }
+
+    #[test]
+    fn normalize_angle_leaves_in_range_angles_untouched() {
+        assert_eq!(normalize_angle(0.0), 0.0);
+    }
+
+    #[test]
+    fn normalize_angle_wraps_values_outside_the_range() {
+        assert!((normalize_angle(PI * 1.5) - (-PI * 0.5)).abs() < 1e-6);
+        assert!((normalize_angle(-PI * 1.5) - (PI * 0.5)).abs() < 1e-6);
+    }
+}
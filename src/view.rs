@@ -0,0 +1,116 @@
+//! Everything to do with putting the game on screen (and, for now, playing
+//! sounds for the events the simulation produced).
+
+use ggez::conf;
+use ggez::graphics::{self, Color, DrawMode, Point2};
+use ggez::audio::Source;
+use ggez::{Context, GameResult};
+use specs::Join;
+
+use components::{Position, Sprite};
+use controllers::EventBuffer;
+use controllers::Event;
+use game_state::PlayerEntity;
+use geometry::{Position as Vector, Size};
+use util::clamp;
+use ApplicationState;
+
+/// The sounds, loaded once up front and reused every frame. No fonts or
+/// images are loaded.
+///
+/// Each sound is played through a plain `ggez::audio::Source` whose volume
+/// is set from the event's distance to the player; sounds are not panned,
+/// pitch-shifted or faded in.
+pub struct Resources {
+    shot_sound: Source,
+    explosion_sound: Source,
+}
+
+impl Resources {
+    /// Loads every resource the game needs.
+    pub fn new(ctx: &mut Context) -> Resources {
+        let shot_sound = Source::new(ctx, "/shot.ogg").expect("could not load shot.ogg");
+        let explosion_sound = Source::new(ctx, "/explosion.ogg").expect("could not load explosion.ogg");
+
+        Resources { shot_sound, explosion_sound }
+    }
+}
+
+/// Creates the ggez context and configures the window.
+pub fn init_rendering_ctx(game_size: Size) -> GameResult<Context> {
+    let mut c = conf::Conf::new();
+    c.window_mode.width = game_size.width as u32;
+    c.window_mode.height = game_size.height as u32;
+    c.window_setup.title = "Rocket".to_owned();
+    Context::load_from_conf("rocket", "rocket", c)
+}
+
+/// Drains the `EventBuffer` resource, playing (and then discarding) a sound
+/// for each event that has one. Sounds are attenuated based on how far
+/// their `position` is from `player_position`.
+pub fn play_sounds(
+    events: &mut EventBuffer,
+    resources: &mut Resources,
+    player_position: Vector,
+    world_size: Size,
+) -> GameResult<()> {
+    for event in events.0.drain(..) {
+        match event {
+            Event::GameStart => (),
+            Event::Shot { position } => {
+                play_positional(&mut resources.shot_sound, position, player_position, world_size)?
+            },
+            Event::Explosion { position } => {
+                play_positional(&mut resources.explosion_sound, position, player_position, world_size)?
+            },
+        }
+    }
+    Ok(())
+}
+
+fn play_positional(
+    source: &mut Source,
+    position: Vector,
+    player_position: Vector,
+    world_size: Size,
+) -> GameResult<()> {
+    let distance = (position - player_position).length();
+    source.set_volume(clamp(1.0 - distance / world_size.width, 0.0, 1.0));
+    source.play()
+}
+
+/// Draws the current frame: every entity with a `Sprite`, as a filled
+/// circle. The `Message` resource is not drawn.
+pub fn render_game(state: &mut ApplicationState, ctx: &mut Context) -> GameResult<()> {
+    graphics::clear(ctx);
+
+    let world = &state.world;
+    let positions = world.read_storage::<Position>();
+    let sprites = world.read_storage::<Sprite>();
+
+    graphics::set_color(ctx, Color::new(1.0, 1.0, 1.0, 1.0))?;
+    for (position, sprite) in (&positions, &sprites).join() {
+        let radius = match *sprite {
+            Sprite::Player => 10.0,
+            Sprite::Enemy => 8.0,
+            Sprite::Bullet => 2.0,
+        };
+        graphics::circle(
+            ctx,
+            DrawMode::Fill,
+            Point2::new(position.0.x, position.0.y),
+            radius,
+            0.5,
+        )?;
+    }
+
+    graphics::present(ctx);
+    Ok(())
+}
+
+/// The player's current position, used to attenuate sounds relative to
+/// them.
+pub fn player_position(state: &ApplicationState) -> Vector {
+    let player_entity = state.world.read_resource::<PlayerEntity>().0.expect("player entity not spawned");
+    state.world.read_storage::<Position>().get(player_entity).expect("player has no Position").0
+}